@@ -1,19 +1,36 @@
 use std::{
     cell::{Ref, RefCell},
-    fmt::{self, Debug, Formatter}, 
-    hash::{Hash, Hasher}, 
-    ops::{Add, AddAssign, Deref, Mul}, 
+    collections::{HashMap, HashSet},
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Deref, Mul},
     rc::Rc
 };
 
+/* Multiplicative identity, needed to seed the gradient of the backward pass root. */
+pub trait One {
+    fn one() -> Self;
+}
+
+impl One for f32 {
+    fn one() -> Self {
+        1.0
+    }
+}
+
+impl One for i32 {
+    fn one() -> Self {
+        1
+    }
+}
+
 /* Marker trait to avoid repetition  */
-/* Note: This currently requires the Copy trait. Can be relaxed to be clone at the cost of cloning members. */
-pub trait ValueTypeTraits: Default + Debug + Mul<Output=Self> + Add<Output=Self> + AddAssign + Copy {}
-impl<T> ValueTypeTraits for T where T: Default + Debug + Mul<Output=Self> + Add<Output=Self> + AddAssign + Copy {}
+pub trait ValueTypeTraits: Default + Debug + Mul<Output=Self> + Add<Output=Self> + AddAssign + Clone + One {}
+impl<T> ValueTypeTraits for T where T: Default + Debug + Mul<Output=Self> + Add<Output=Self> + AddAssign + Clone + One {}
 
 type DefaultValueType = f32;
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 enum Operation {
     None,
     Add,
@@ -39,11 +56,132 @@ impl<T> Value<T>
     }
 
     pub fn data(&self) -> T {
-        self.borrow().data
+        self.borrow().data.clone()
     }
 
     pub fn grad(&self) -> T {
-        self.borrow().grad
+        self.borrow().grad.clone()
+    }
+
+    /* Runs reverse-mode autodiff over the DAG rooted at self. Seeds this node's
+       grad with the multiplicative identity, then replays every node's stored
+       `backward` fn in reverse topological order so gradients accumulate into
+       each child via `+=`. Call `zero_grad()` first if this isn't the first
+       backward pass over the graph. */
+    pub fn backward(&self) {
+        let mut topo: Vec<Value<T>> = Vec::new();
+        let mut visited: HashSet<*const RefCell<ValueImpl<T>>> = HashSet::new();
+        build_topo(self, &mut visited, &mut topo);
+
+        self.borrow_mut().grad = T::one();
+
+        for node in topo.iter().rev() {
+            let backward_fn = node.borrow().backward;
+            if let Some(f) = backward_fn {
+                f(node.borrow());
+            }
+        }
+    }
+
+    /* Resets grad to T::default() on every node reachable from self, so a
+       fresh backward() pass doesn't accumulate on top of a previous one. */
+    pub fn zero_grad(&self) {
+        let mut visited: HashSet<*const RefCell<ValueImpl<T>>> = HashSet::new();
+        zero_grad_rec(self, &mut visited);
+    }
+
+    /* Duplicates the whole reachable DAG into fresh Rc/RefCell nodes, so the
+       result can be mutated (e.g. a perturbed forward pass, a checkpoint)
+       without aliasing self. A node reached through multiple parents is
+       cloned once and shared in the result, keeping the clone structurally
+       identical to the original instead of unrolling it into a tree. */
+    pub fn deep_clone(&self) -> Value<T> {
+        let mut clones: HashMap<*const RefCell<ValueImpl<T>>, Value<T>> = HashMap::new();
+        deep_clone_rec(self, &mut clones);
+        clones.get(&Rc::as_ptr(self)).expect("root was just cloned above").clone()
+    }
+}
+
+/* Depth-first post-order traversal over `prev`, deduplicated on Rc pointer
+   identity so diamond-shaped graphs (a node reached via two parents) are
+   visited once and end up before both of their parents in `topo`. */
+fn build_topo<T>(
+    value: &Value<T>,
+    visited: &mut HashSet<*const RefCell<ValueImpl<T>>>,
+    topo: &mut Vec<Value<T>>,
+)
+    where T: ValueTypeTraits
+{
+    if visited.insert(Rc::as_ptr(value)) {
+        for child in value.borrow().prev.iter() {
+            build_topo(child, visited, topo);
+        }
+        topo.push(value.clone());
+    }
+}
+
+fn zero_grad_rec<T>(value: &Value<T>, visited: &mut HashSet<*const RefCell<ValueImpl<T>>>)
+    where T: ValueTypeTraits
+{
+    if visited.insert(Rc::as_ptr(value)) {
+        value.borrow_mut().grad = T::default();
+        for child in value.borrow().prev.iter() {
+            zero_grad_rec(child, visited);
+        }
+    }
+}
+
+/* Iterative post-order walk (explicit stack, not the call stack) so deep
+   graphs don't overflow. A node's Discover frame pushes its children, and
+   its Build frame clones it once they're all memoized in `clones`, keyed on
+   the *source* node's Rc pointer identity. A node reached through several
+   parents may be discovered more than once, but is cloned only once. */
+fn deep_clone_rec<T>(root: &Value<T>, clones: &mut HashMap<*const RefCell<ValueImpl<T>>, Value<T>>)
+    where T: ValueTypeTraits
+{
+    enum Frame<T> where T: ValueTypeTraits {
+        Discover(Value<T>),
+        Build(Value<T>),
+    }
+
+    let mut stack = vec![Frame::Discover(root.clone())];
+
+    while let Some(frame) = stack.pop() {
+        match frame {
+            Frame::Discover(node) => {
+                if clones.contains_key(&Rc::as_ptr(&node)) {
+                    continue;
+                }
+                stack.push(Frame::Build(node.clone()));
+                for child in node.borrow().prev.iter() {
+                    if !clones.contains_key(&Rc::as_ptr(child)) {
+                        stack.push(Frame::Discover(child.clone()));
+                    }
+                }
+            }
+            Frame::Build(node) => {
+                let ptr = Rc::as_ptr(&node);
+                if clones.contains_key(&ptr) {
+                    continue;
+                }
+
+                let cloned = {
+                    let src = node.borrow();
+                    let cloned_children: Vec<Value<T>> = src.prev.iter()
+                        .map(|child| clones.get(&Rc::as_ptr(child))
+                            .expect("child is deep-cloned before its parent")
+                            .clone())
+                        .collect();
+
+                    let mut new_impl = ValueImpl::new(src.data.clone(), cloned_children, src.op);
+                    new_impl.grad = src.grad.clone();
+                    new_impl.backward = src.backward;
+                    Value::new(new_impl)
+                };
+
+                clones.insert(ptr, cloned);
+            }
+        }
     }
 }
 
@@ -79,17 +217,26 @@ impl<'a, 'b, T> Add<&'b Value<T>> for &'a Value<T>
 
     fn add(self, other: &'b Value<T>) -> Self::Output {
         let mut out: ValueImpl<T> = ValueImpl::new(
-            self.borrow().data + other.borrow().data, 
-            vec![self.clone(), other.clone()], 
+            self.borrow().data.clone() + other.borrow().data.clone(),
+            vec![self.clone(), other.clone()],
             Operation::Add
         );
-        
-        out.backward = Some(|out_ref| {
-            let mut child1 = out_ref.prev[0].borrow_mut();
-            let mut child2 = out_ref.prev[1].borrow_mut();
 
-            child1.grad += out_ref.grad;
-            child2.grad += out_ref.grad;
+        out.backward = Some(|out_ref| {
+            // A node can appear as both operands (e.g. `&v + &v`), in which case
+            // prev[0] and prev[1] alias the same RefCell and a second borrow_mut
+            // would panic; fold both contributions into the single borrow instead.
+            if Rc::ptr_eq(&out_ref.prev[0], &out_ref.prev[1]) {
+                let mut child = out_ref.prev[0].borrow_mut();
+                child.grad += out_ref.grad.clone();
+                child.grad += out_ref.grad.clone();
+            } else {
+                let mut child1 = out_ref.prev[0].borrow_mut();
+                let mut child2 = out_ref.prev[1].borrow_mut();
+
+                child1.grad += out_ref.grad.clone();
+                child2.grad += out_ref.grad.clone();
+            }
         });
 
         Value::new(out)
@@ -97,7 +244,7 @@ impl<'a, 'b, T> Add<&'b Value<T>> for &'a Value<T>
 }
 
 /* Consuming add, convenience method */
-impl<T> Add for Value<T> 
+impl<T> Add for Value<T>
     where T: ValueTypeTraits 
 {
     type Output = Value<T>;
@@ -113,17 +260,26 @@ impl<'a, 'b, T> Mul<&'b Value<T>> for &'a Value<T>
 
     fn mul(self, other: &'b Value<T>) -> Self::Output {
         let mut out: ValueImpl<T> = ValueImpl::new(
-            self.borrow().data * other.borrow().data, 
-            vec![self.clone(), other.clone()], 
+            self.borrow().data.clone() * other.borrow().data.clone(),
+            vec![self.clone(), other.clone()],
             Operation::Multiply
         );
-        
-        out.backward = Some(|out_ref| {
-            let mut child1 = out_ref.prev[0].borrow_mut();
-            let mut child2 = out_ref.prev[1].borrow_mut();
 
-            child1.grad += child2.data * out_ref.grad;
-            child2.grad += child1.data * out_ref.grad;
+        out.backward = Some(|out_ref| {
+            // See the Add impl above: self-multiplication (`&v * &v`) aliases
+            // prev[0] and prev[1] on the same RefCell, so borrow once in that case.
+            if Rc::ptr_eq(&out_ref.prev[0], &out_ref.prev[1]) {
+                let mut child = out_ref.prev[0].borrow_mut();
+                let data = child.data.clone();
+                child.grad += data.clone() * out_ref.grad.clone();
+                child.grad += data * out_ref.grad.clone();
+            } else {
+                let mut child1 = out_ref.prev[0].borrow_mut();
+                let mut child2 = out_ref.prev[1].borrow_mut();
+
+                child1.grad += child2.data.clone() * out_ref.grad.clone();
+                child2.grad += child1.data.clone() * out_ref.grad.clone();
+            }
         });
 
         Value::new(out)
@@ -131,7 +287,7 @@ impl<'a, 'b, T> Mul<&'b Value<T>> for &'a Value<T>
 }
 
 /* Consuming add, convenience method */
-impl<T> Mul for Value<T> 
+impl<T> Mul for Value<T>
     where T: ValueTypeTraits 
 {
     type Output = Value<T>;
@@ -260,6 +416,11 @@ mod tests {
             Wrap(0)
         }
     }
+    impl One for Wrap {
+        fn one() -> Self {
+            Wrap(1)
+        }
+    }
 
     #[test]
     fn test_add_struct() {
@@ -271,4 +432,214 @@ mod tests {
         let v4 = v1 + v2; // consuming add
         assert_eq!(v4.data().0, 4);
     }
+
+    #[test]
+    fn test_backward_simple_add() {
+        let a: Value = Value::from(2.0);
+        let b = Value::from(3.0);
+        let c = &a + &b;
+        c.backward();
+
+        assert_eq!(c.grad(), 1.0);
+        assert_eq!(a.grad(), 1.0);
+        assert_eq!(b.grad(), 1.0);
+    }
+
+    #[test]
+    fn test_backward_diamond_shape() {
+        // d = (a + b) + (a * b), so a and b are each reached through two
+        // different parents (x and y) before meeting again at d.
+        let a: Value = Value::from(2.0);
+        let b = Value::from(3.0);
+        let x = &a + &b;
+        let y = &a * &b;
+        let d = &x + &y;
+        d.backward();
+
+        // dd/da = 1 + b = 4, dd/db = 1 + a = 3
+        assert_eq!(a.grad(), 4.0);
+        assert_eq!(b.grad(), 3.0);
+        assert_eq!(x.grad(), 1.0);
+        assert_eq!(y.grad(), 1.0);
+    }
+
+    #[test]
+    fn test_zero_grad_resets_reachable_graph() {
+        let a: Value = Value::from(2.0);
+        let b = Value::from(3.0);
+        let c = &a + &b;
+        c.backward();
+        assert_eq!(a.grad(), 1.0);
+
+        c.zero_grad();
+        assert_eq!(c.grad(), 0.0);
+        assert_eq!(a.grad(), 0.0);
+        assert_eq!(b.grad(), 0.0);
+    }
+
+    #[test]
+    fn test_backward_self_multiply_does_not_panic() {
+        // `&v * &v` makes prev[0] and prev[1] alias the same node; backward
+        // must fold both contributions instead of double-borrowing it.
+        let v: Value = Value::from(3.0);
+        let squared = &v * &v;
+        squared.backward();
+
+        // d(v^2)/dv = 2v = 6
+        assert_eq!(v.grad(), 6.0);
+        assert_eq!(squared.data(), 9.0);
+    }
+
+    #[test]
+    fn test_backward_self_add_does_not_panic() {
+        let v: Value = Value::from(3.0);
+        let doubled = &v + &v;
+        doubled.backward();
+
+        assert_eq!(v.grad(), 2.0);
+        assert_eq!(doubled.data(), 6.0);
+    }
+
+    #[test]
+    fn test_deep_clone_does_not_alias_original() {
+        let a: Value = Value::from(2.0);
+        let b = Value::from(3.0);
+        let c = &a + &b;
+
+        let cloned = c.deep_clone();
+        assert_eq!(cloned.data(), c.data());
+
+        // Mutating the clone's graph must not touch the original.
+        cloned.backward();
+        assert_eq!(cloned.grad(), 1.0);
+        assert_eq!(c.grad(), 0.0);
+    }
+
+    // A deliberately non-Copy value type (a Vec-backed bignum) to lock in
+    // that ValueTypeTraits only needs Clone, not Copy.
+    #[derive(Debug, Clone, PartialEq)]
+    struct BigNum(Vec<i64>); // little-endian base-10 digits
+
+    impl BigNum {
+        fn from_u64(mut n: u64) -> Self {
+            if n == 0 {
+                return BigNum(vec![0]);
+            }
+            let mut digits = Vec::new();
+            while n > 0 {
+                digits.push((n % 10) as i64);
+                n /= 10;
+            }
+            BigNum(digits)
+        }
+    }
+
+    impl Add for BigNum {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self::Output {
+            let len = self.0.len().max(rhs.0.len());
+            let mut result = Vec::with_capacity(len + 1);
+            let mut carry = 0i64;
+            for i in 0..len {
+                let sum = self.0.get(i).unwrap_or(&0) + rhs.0.get(i).unwrap_or(&0) + carry;
+                result.push(sum % 10);
+                carry = sum / 10;
+            }
+            if carry > 0 {
+                result.push(carry);
+            }
+            BigNum(result)
+        }
+    }
+
+    impl Mul for BigNum {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self::Output {
+            let mut result = vec![0i64; self.0.len() + rhs.0.len()];
+            for (i, a) in self.0.iter().enumerate() {
+                for (j, b) in rhs.0.iter().enumerate() {
+                    result[i + j] += a * b;
+                }
+            }
+            let mut carry = 0i64;
+            for digit in result.iter_mut() {
+                *digit += carry;
+                carry = *digit / 10;
+                *digit %= 10;
+            }
+            while carry > 0 {
+                result.push(carry % 10);
+                carry /= 10;
+            }
+            while result.len() > 1 && *result.last().unwrap() == 0 {
+                result.pop();
+            }
+            BigNum(result)
+        }
+    }
+
+    impl AddAssign for BigNum {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = self.clone() + rhs;
+        }
+    }
+
+    impl Default for BigNum {
+        fn default() -> Self {
+            BigNum(vec![0])
+        }
+    }
+
+    impl One for BigNum {
+        fn one() -> Self {
+            BigNum(vec![1])
+        }
+    }
+
+    #[test]
+    fn test_add_non_copy_bignum() {
+        let v1: Value<BigNum> = Value::from(BigNum::from_u64(123_456));
+        let v2 = Value::from(BigNum::from_u64(987_654));
+
+        let sum = &v1 + &v2;
+        assert_eq!(sum.data(), BigNum::from_u64(123_456 + 987_654));
+
+        // data() must not move out of the node, so it can still be read again.
+        assert_eq!(v1.data(), BigNum::from_u64(123_456));
+    }
+
+    #[test]
+    fn test_backward_non_copy_bignum() {
+        let a: Value<BigNum> = Value::from(BigNum::from_u64(6));
+        let b = Value::from(BigNum::from_u64(7));
+        let product = &a * &b;
+        product.backward();
+
+        // d(a*b)/da = b, d(a*b)/db = a
+        assert_eq!(a.grad(), BigNum::from_u64(7));
+        assert_eq!(b.grad(), BigNum::from_u64(6));
+        assert_eq!(product.data(), BigNum::from_u64(42));
+    }
+
+    #[test]
+    fn test_deep_clone_copies_grad_independently_of_source() {
+        // d = (a + b) + (a * b): a and b are each shared by two parents.
+        let a: Value = Value::from(2.0);
+        let b = Value::from(3.0);
+        let x = &a + &b;
+        let y = &a * &b;
+        let d = &x + &y;
+
+        d.backward();
+        assert_eq!(d.grad(), 1.0);
+
+        let cloned = d.deep_clone();
+        assert_eq!(cloned.data(), d.data());
+        assert_eq!(cloned.grad(), d.grad());
+
+        // Resetting the source's grads must not reach back into the clone.
+        d.zero_grad();
+        assert_eq!(d.grad(), 0.0);
+        assert_eq!(cloned.grad(), 1.0);
+    }
 }
\ No newline at end of file
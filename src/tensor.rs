@@ -0,0 +1,265 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Mul};
+
+use crate::engine::{Value, ValueTypeTraits};
+
+/// Two tensors (or a tensor and a value) were combined element-wise but had
+/// a different number of components.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ShapeMismatch {
+    pub left_len: usize,
+    pub right_len: usize,
+}
+
+impl Display for ShapeMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "shape mismatch: {} vs {} elements", self.left_len, self.right_len)
+    }
+}
+
+impl std::error::Error for ShapeMismatch {}
+
+/// A fixed-length vector of `Value<T>`, so a model can be built out of
+/// element-wise and reduction ops instead of chaining individual `Value`
+/// operations by hand. Every element stays wired into the same autograd
+/// graph as the underlying `Value`s it was built from.
+#[derive(Debug, Clone)]
+pub struct Tensor<T = f32>
+    where T: ValueTypeTraits
+{
+    elements: Vec<Value<T>>,
+}
+
+impl<T> Tensor<T>
+    where T: ValueTypeTraits
+{
+    /// Builds independent leaf `Value`s from an iterator of raw values.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_iter<I, U>(values: I) -> Self
+        where
+            I: IntoIterator<Item = U>,
+            U: Into<T>,
+    {
+        Tensor {
+            elements: values.into_iter().map(Value::from).collect(),
+        }
+    }
+
+    /// Builds `n` independent leaf `Value`s, each holding `T::default()`.
+    pub fn zeros(n: usize) -> Self {
+        Tensor {
+            elements: (0..n).map(|_| Value::default()).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn elements(&self) -> &[Value<T>] {
+        &self.elements
+    }
+
+    fn check_same_len(&self, other: &Tensor<T>) -> Result<(), ShapeMismatch> {
+        if self.len() != other.len() {
+            return Err(ShapeMismatch { left_len: self.len(), right_len: other.len() });
+        }
+        Ok(())
+    }
+
+    fn zip_with(
+        &self,
+        other: &Tensor<T>,
+        op: impl Fn(&Value<T>, &Value<T>) -> Value<T>,
+    ) -> Result<Tensor<T>, ShapeMismatch> {
+        self.check_same_len(other)?;
+
+        Ok(Tensor {
+            elements: self.elements.iter()
+                .zip(other.elements.iter())
+                .map(|(a, b)| op(a, b))
+                .collect(),
+        })
+    }
+
+    /// Broadcasts a scalar `Value` across every element via multiplication.
+    pub fn scale(&self, scalar: &Value<T>) -> Tensor<T> {
+        Tensor {
+            elements: self.elements.iter().map(|v| v * scalar).collect(),
+        }
+    }
+
+    /// Sums the element-wise products into a single `Value`, wired into the
+    /// autograd graph so `backward()` distributes gradient back to every
+    /// component of both tensors.
+    pub fn dot(&self, other: &Tensor<T>) -> Result<Value<T>, ShapeMismatch> {
+        self.check_same_len(other)?;
+
+        let products = self.elements.iter()
+            .zip(other.elements.iter())
+            .map(|(a, b)| a * b);
+        Ok(sum(products))
+    }
+
+    /// Squared Euclidean norm: the dot product of this tensor with itself.
+    pub fn squared_norm(&self) -> Value<T> {
+        let products = self.elements.iter().map(|v| v * v);
+        sum(products)
+    }
+}
+
+/// Folds an iterator of `Value`s into one `Value` via repeated `Add`, so the
+/// result is a single summed node reachable from every addend.
+fn sum<T>(mut values: impl Iterator<Item = Value<T>>) -> Value<T>
+    where T: ValueTypeTraits
+{
+    let first = values.next().unwrap_or_default();
+    values.fold(first, |acc, v| &acc + &v)
+}
+
+impl<'a, 'b, T> Add<&'b Tensor<T>> for &'a Tensor<T>
+    where T: ValueTypeTraits
+{
+    type Output = Result<Tensor<T>, ShapeMismatch>;
+
+    fn add(self, other: &'b Tensor<T>) -> Self::Output {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+/* Consuming add, convenience method */
+impl<T> Add for Tensor<T>
+    where T: ValueTypeTraits
+{
+    type Output = Result<Tensor<T>, ShapeMismatch>;
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b Tensor<T>> for &'a Tensor<T>
+    where T: ValueTypeTraits
+{
+    type Output = Result<Tensor<T>, ShapeMismatch>;
+
+    fn mul(self, other: &'b Tensor<T>) -> Self::Output {
+        self.zip_with(other, |a, b| a * b)
+    }
+}
+
+/* Consuming mul, convenience method */
+impl<T> Mul for Tensor<T>
+    where T: ValueTypeTraits
+{
+    type Output = Result<Tensor<T>, ShapeMismatch>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a, 'b, T> Mul<&'b Value<T>> for &'a Tensor<T>
+    where T: ValueTypeTraits
+{
+    type Output = Tensor<T>;
+
+    fn mul(self, scalar: &'b Value<T>) -> Self::Output {
+        self.scale(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter_and_zeros_build_independent_leaves() {
+        let t: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+        assert_eq!(t.len(), 3);
+        assert_eq!(t.elements()[0].data(), 1.0);
+        assert_eq!(t.elements()[2].data(), 3.0);
+
+        let z: Tensor = Tensor::zeros(4);
+        assert_eq!(z.len(), 4);
+        assert!(z.elements().iter().all(|v| v.data() == 0.0));
+
+        // Mutating one leaf's graph must not affect its siblings.
+        z.elements()[0].backward();
+        assert_eq!(z.elements()[0].grad(), 1.0);
+        assert_eq!(z.elements()[1].grad(), 0.0);
+    }
+
+    #[test]
+    fn test_elementwise_add_and_mul() {
+        let a: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+        let b: Tensor = Tensor::from_iter([10.0, 20.0, 30.0]);
+
+        let sum = (&a + &b).expect("same length");
+        assert_eq!(sum.elements()[0].data(), 11.0);
+        assert_eq!(sum.elements()[2].data(), 33.0);
+
+        let product = (&a * &b).expect("same length");
+        assert_eq!(product.elements()[1].data(), 40.0);
+    }
+
+    #[test]
+    fn test_elementwise_shape_mismatch_is_an_error() {
+        let a: Tensor = Tensor::from_iter([1.0, 2.0]);
+        let b: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+
+        assert_eq!((&a + &b).unwrap_err(), ShapeMismatch { left_len: 2, right_len: 3 });
+        assert_eq!((&a * &b).unwrap_err(), ShapeMismatch { left_len: 2, right_len: 3 });
+    }
+
+    #[test]
+    fn test_scalar_broadcast() {
+        let t: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+        let factor = Value::from(2.0);
+
+        let scaled = &t * &factor;
+        assert_eq!(scaled.elements()[0].data(), 2.0);
+        assert_eq!(scaled.elements()[2].data(), 6.0);
+    }
+
+    #[test]
+    fn test_dot_product_backward_flows_to_each_component() {
+        let a: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+        let b: Tensor = Tensor::from_iter([4.0, 5.0, 6.0]);
+
+        let dot = a.dot(&b).expect("same length");
+        assert_eq!(dot.data(), 1.0 * 4.0 + 2.0 * 5.0 + 3.0 * 6.0);
+
+        dot.backward();
+        // d(dot)/da_i = b_i, d(dot)/db_i = a_i
+        assert_eq!(a.elements()[0].grad(), 4.0);
+        assert_eq!(a.elements()[1].grad(), 5.0);
+        assert_eq!(a.elements()[2].grad(), 6.0);
+        assert_eq!(b.elements()[0].grad(), 1.0);
+        assert_eq!(b.elements()[1].grad(), 2.0);
+        assert_eq!(b.elements()[2].grad(), 3.0);
+    }
+
+    #[test]
+    fn test_squared_norm_backward_flows_to_each_component() {
+        let t: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+        let norm = t.squared_norm();
+        assert_eq!(norm.data(), 1.0 + 4.0 + 9.0);
+
+        norm.backward();
+        // d(sum v_i^2)/dv_i = 2 * v_i
+        assert_eq!(t.elements()[0].grad(), 2.0);
+        assert_eq!(t.elements()[1].grad(), 4.0);
+        assert_eq!(t.elements()[2].grad(), 6.0);
+    }
+
+    #[test]
+    fn test_dot_product_shape_mismatch_is_an_error() {
+        let a: Tensor = Tensor::from_iter([1.0, 2.0]);
+        let b: Tensor = Tensor::from_iter([1.0, 2.0, 3.0]);
+
+        assert_eq!(a.dot(&b).unwrap_err(), ShapeMismatch { left_len: 2, right_len: 3 });
+    }
+}